@@ -0,0 +1,232 @@
+#![cfg(feature = "cache")]
+
+use crate::error::Result;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A pluggable cache for raw HTTP response bodies. Keys are the full request url, including the
+/// locale query parameters, so two requests only share an entry if they would have produced the
+/// exact same request.
+pub trait ResponseCache: Send + Sync {
+    /// Returns the cached bytes for `key`, or `None` if there is no entry or it has expired.
+    fn get(&self, key: &str) -> Option<Bytes>;
+    /// Stores `bytes` under `key`, to be considered expired after `ttl` has passed.
+    fn put(&self, key: &str, bytes: Bytes, ttl: Duration);
+}
+
+/// Coarse groups of endpoints which should share a TTL, since how often their data actually
+/// changes varies a lot (the simulcast season list barely ever changes, search results do
+/// constantly).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum CacheClass {
+    Browse,
+    Query,
+    SimulcastSeasons,
+    Other,
+}
+
+/// Caching configuration installed process-wide via [`set_cache`].
+#[derive(Clone)]
+pub struct CacheConfig {
+    cache: Arc<dyn ResponseCache>,
+    default_ttl: Duration,
+    ttls: HashMap<CacheClass, Duration>,
+}
+
+impl CacheConfig {
+    /// Creates a new cache configuration with a default ttl of 5 minutes, applied to any
+    /// [`CacheClass`] without an explicit override.
+    pub fn new(cache: impl ResponseCache + 'static) -> Self {
+        Self {
+            cache: Arc::new(cache),
+            default_ttl: Duration::from_secs(5 * 60),
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// Overrides the default ttl used when no [`CacheClass`]-specific one was set.
+    pub fn default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// Sets the ttl used for requests belonging to `class`.
+    pub fn ttl(mut self, class: CacheClass, ttl: Duration) -> Self {
+        self.ttls.insert(class, ttl);
+        self
+    }
+
+    pub(crate) fn ttl_for(&self, class: CacheClass) -> Duration {
+        self.ttls.get(&class).copied().unwrap_or(self.default_ttl)
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<Bytes> {
+        self.cache.get(key)
+    }
+
+    pub(crate) fn put(&self, key: &str, bytes: Bytes, class: CacheClass) {
+        self.cache.put(key, bytes, self.ttl_for(class));
+    }
+}
+
+static CACHE: OnceLock<CacheConfig> = OnceLock::new();
+
+/// Installs a [`CacheConfig`] that cache-aware endpoints consult before making their request.
+/// The only such endpoint is [`crate::Crunchyroll::simulcast_seasons`];
+/// [`crate::Crunchyroll::browse`] and [`crate::Crunchyroll::query`] always make their request.
+/// Only takes effect the first time it's called.
+pub fn set_cache(config: CacheConfig) {
+    let _ = CACHE.set(config);
+}
+
+/// Runs `fetch` (the actual `.get(...).request()` call) unless [`set_cache`] was called and
+/// already has a live entry for `key`, in which case the cached response is deserialized
+/// directly and `fetch` - the network call - is skipped entirely. On a miss, the freshly
+/// fetched value is stored under `key` for next time.
+pub(crate) async fn cached<T, F, Fut>(class: CacheClass, key: &str, fetch: F) -> Result<T>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let config = match CACHE.get() {
+        Some(config) => config,
+        None => return fetch().await,
+    };
+
+    if let Some(bytes) = config.get(key) {
+        if let Ok(value) = serde_json::from_slice(&bytes) {
+            return Ok(value);
+        }
+    }
+
+    let value = fetch().await?;
+
+    if let Ok(bytes) = serde_json::to_vec(&value) {
+        config.put(key, Bytes::from(bytes), class);
+    }
+
+    Ok(value)
+}
+
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct FileCacheEntry {
+    expires_at: u64,
+    data: Vec<u8>,
+}
+
+/// A [`ResponseCache`] backed by a single JSON file on disk, suitable for CLI tools which just
+/// want repeated invocations to not constantly re-hit the Crunchyroll API.
+pub struct FileCache {
+    path: PathBuf,
+    entries: Mutex<HashMap<String, FileCacheEntry>>,
+}
+
+impl FileCache {
+    /// Opens (or lazily creates, on first [`FileCache::put`]) a JSON-file-backed cache at
+    /// `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let entries = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+
+        Self {
+            path,
+            entries: Mutex::new(entries),
+        }
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    fn persist(&self, entries: &HashMap<String, FileCacheEntry>) {
+        if let Ok(json) = serde_json::to_vec(entries) {
+            let _ = fs::write(&self.path, json);
+        }
+    }
+}
+
+impl ResponseCache for FileCache {
+    fn get(&self, key: &str) -> Option<Bytes> {
+        let entries = self.entries.lock().unwrap();
+        let entry = entries.get(key)?;
+        if entry.expires_at <= Self::now() {
+            return None;
+        }
+        Some(Bytes::from(entry.data.clone()))
+    }
+
+    fn put(&self, key: &str, bytes: Bytes, ttl: Duration) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(
+            key.to_string(),
+            FileCacheEntry {
+                expires_at: Self::now() + ttl.as_secs(),
+                data: bytes.to_vec(),
+            },
+        );
+        self.persist(&entries);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // `cached()` is `pub(crate)`, so the only way to actually exercise it (rather than the
+    // `ResponseCache` impls it calls into) is from inside this module. `set_cache` is a one-shot
+    // `OnceLock`, so this has to be a single test that covers both the miss and the hit instead
+    // of two independent ones.
+    #[tokio::test]
+    async fn cached_skips_fetch_on_repeat_key() {
+        let path = std::env::temp_dir().join(format!(
+            "crunchyroll-rs-test-cached-fn-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        set_cache(CacheConfig::new(FileCache::new(&path)));
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let key = "https://www.crunchyroll.com/content/v1/cached-fn-test?locale=en-US";
+
+        let miss_calls = calls.clone();
+        let miss: u32 = cached(CacheClass::Other, key, || async move {
+            miss_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(42)
+        })
+        .await
+        .unwrap();
+        assert_eq!(miss, 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let hit_calls = calls.clone();
+        let hit: u32 = cached(CacheClass::Other, key, || async move {
+            hit_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(0)
+        })
+        .await
+        .unwrap();
+        assert_eq!(hit, 42, "a cache hit should return the stored value, not re-fetch");
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            1,
+            "fetch must not run again on a cache hit"
+        );
+
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,80 @@
+mod calendar {
+    use crate::common::BulkResult;
+    use crate::media::{Episode, Media, Series};
+    use crate::{options, Crunchyroll, Executor, Request, Result};
+    use chrono::{DateTime, NaiveDate, Utc};
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    /// A single episode expected to premiere, as returned by [`Crunchyroll::release_calendar`].
+    #[derive(Clone, Debug, Default, Deserialize, Request)]
+    #[request(executor(series, episode))]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct CalendarEntry {
+        #[serde(skip)]
+        executor: Arc<Executor>,
+
+        pub series: Media<Series>,
+        pub episode: Media<Episode>,
+        pub episode_number: u32,
+        pub available_date: DateTime<Utc>,
+        pub premium_available_date: DateTime<Utc>,
+    }
+
+    /// All [`CalendarEntry`]s expected to premiere on a single day, sorted by
+    /// [`CalendarEntry::available_date`].
+    #[derive(Clone, Debug, Default)]
+    pub struct CalendarDay {
+        pub date: NaiveDate,
+        pub entries: Vec<CalendarEntry>,
+    }
+
+    options! {
+        ReleaseCalendarOptions;
+        /// Only include episodes of series on the account's watchlist / followed series.
+        watchlist_only(bool, "watchlist_only") = Some(false),
+        /// Limit of results to return.
+        limit(u32, "n") = Some(100),
+        /// Specifies the index from which the entries should be returned.
+        start(u32, "start") = None
+    }
+
+    impl Crunchyroll {
+        /// Returns the airing schedule (which episodes are expected to premiere and when),
+        /// grouped by day, so a downstream notifier can diff today's schedule against what it
+        /// has already announced instead of scraping it from elsewhere.
+        pub async fn release_calendar(
+            &self,
+            options: ReleaseCalendarOptions,
+        ) -> Result<Vec<CalendarDay>> {
+            let endpoint = "https://www.crunchyroll.com/content/v1/release_calendar";
+            let result: BulkResult<CalendarEntry> = self
+                .executor
+                .get(endpoint)
+                .query(&options.into_query())
+                .apply_locale_query()
+                .request()
+                .await?;
+
+            let mut by_day: BTreeMap<NaiveDate, Vec<CalendarEntry>> = BTreeMap::new();
+            for entry in result.items {
+                by_day
+                    .entry(entry.available_date.date_naive())
+                    .or_default()
+                    .push(entry);
+            }
+
+            Ok(by_day
+                .into_iter()
+                .map(|(date, mut entries)| {
+                    entries.sort_by_key(|e| e.available_date);
+                    CalendarDay { date, entries }
+                })
+                .collect())
+        }
+    }
+}
+
+pub use calendar::*;
@@ -0,0 +1,99 @@
+mod curated_feed {
+    use crate::common::{BulkResult, FromId, Request};
+    use crate::{options, Crunchyroll, Executor, MediaCollection, Result};
+    use serde::Deserialize;
+    use std::sync::Arc;
+
+    /// A curated feed panel, e.g. the front-page "discover" rows shown in the app (hero
+    /// carousels, "because you watched", curated collections, ...).
+    #[derive(Clone, Debug, Default, Deserialize, Request)]
+    #[request(executor(items))]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct CuratedFeed {
+        #[serde(skip)]
+        executor: Arc<Executor>,
+
+        pub id: String,
+        pub channel_id: String,
+        pub title: String,
+        pub description: String,
+        pub items: Vec<MediaCollection>,
+    }
+
+    impl FromId for CuratedFeed {
+        async fn from_id(crunchyroll: &Crunchyroll, id: String) -> Result<Self> {
+            let endpoint = format!(
+                "https://www.crunchyroll.com/content/v1/curated_feeds/{}",
+                id
+            );
+            crunchyroll
+                .executor
+                .get(endpoint)
+                .apply_locale_query()
+                .request()
+                .await
+        }
+    }
+
+    /// The contents of a single home feed panel.
+    ///
+    /// This intentionally doesn't reuse [`CuratedFeed`] directly: [`HomeFeedItem`] is internally
+    /// tagged on `resource_type`, which means serde re-feeds the *whole* panel object - tag key
+    /// included - to this struct's `Deserialize` impl. Denying unknown fields here (as
+    /// `CuratedFeed` does under `__test_strict`) would therefore reject `resource_type` itself on
+    /// every real payload.
+    #[derive(Clone, Debug, Default, Deserialize)]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct HomeFeedPanel {
+        pub id: String,
+        pub channel_id: String,
+        pub title: String,
+        pub description: String,
+        pub items: Vec<MediaCollection>,
+    }
+
+    /// A single panel of the discovery home feed. Which variant is returned depends on how
+    /// Crunchyroll decided to present the panel on that day, not on anything the caller can
+    /// choose ahead of time.
+    #[derive(Clone, Debug, Deserialize)]
+    #[serde(tag = "resource_type", rename_all = "snake_case")]
+    pub enum HomeFeedItem {
+        /// A large, auto-rotating carousel, usually shown at the top of the home feed.
+        HeroCarousel(HomeFeedPanel),
+        /// A row recommended off the back of something the account already watched.
+        BecauseYouWatched(HomeFeedPanel),
+        /// A hand-picked collection of series/movies, e.g. a seasonal highlight reel.
+        CuratedCollection(HomeFeedPanel),
+        /// Any panel kind which is not explicitly modeled (yet).
+        #[serde(other)]
+        Unknown,
+    }
+
+    options! {
+        HomeFeedOptions;
+        /// Limit of results to return.
+        limit(u32, "n") = Some(20),
+        /// Specifies the index from which the entries should be returned.
+        start(u32, "start") = None
+    }
+
+    impl Crunchyroll {
+        /// Returns the panels of the discovery home feed (what's usually shown on the app's
+        /// front page) in the order Crunchyroll wants them displayed.
+        pub async fn home_feed(
+            &self,
+            options: HomeFeedOptions,
+        ) -> Result<BulkResult<HomeFeedItem>> {
+            let endpoint = "https://www.crunchyroll.com/content/v1/home_feed";
+            self.executor
+                .get(endpoint)
+                .query(&options.into_query())
+                .apply_locale_query()
+                .request()
+                .await
+        }
+    }
+}
+
+pub use curated_feed::*;
@@ -6,6 +6,12 @@ mod internal;
 mod media_collection;
 mod common;
 mod media;
+mod feed;
+mod pager;
+mod matcher;
+mod calendar;
+#[cfg(feature = "cache")]
+mod cache;
 
 #[cfg(feature = "__test_strict")]
 use internal::strict::StrictValue;
@@ -20,3 +26,28 @@ pub use media_collection::Series;
 
 pub use media::Episode;
 pub use media::Movie;
+
+pub use feed::CuratedFeed;
+pub use feed::HomeFeedItem;
+pub use feed::HomeFeedOptions;
+pub use feed::HomeFeedPanel;
+
+#[cfg(feature = "cache")]
+pub use cache::CacheClass;
+#[cfg(feature = "cache")]
+pub use cache::CacheConfig;
+#[cfg(feature = "cache")]
+pub use cache::FileCache;
+#[cfg(feature = "cache")]
+pub use cache::ResponseCache;
+#[cfg(feature = "cache")]
+pub use cache::set_cache;
+
+pub use matcher::{
+    match_filename, match_filenames, parse_filename, title_similarity, MatchOptions, MatchedMedia,
+    MatchResult, ParsedFilename,
+};
+
+pub use calendar::CalendarDay;
+pub use calendar::CalendarEntry;
+pub use calendar::ReleaseCalendarOptions;
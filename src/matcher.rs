@@ -0,0 +1,311 @@
+use crate::error::Result;
+use crate::media::{Episode, Media, MovieListing, Season, Series};
+use crate::search::{QueryOptions, QueryType};
+use crate::Crunchyroll;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// A filename, broken down into the pieces needed to look it up against the Crunchyroll
+/// catalog.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsedFilename {
+    /// The cleaned up, query-ready title, with release group tags, resolution/codec tags,
+    /// checksums and episode markers stripped out.
+    pub title: String,
+    /// The season number, if the filename encoded one (e.g. `S06E12`, `6x12`). Absent for
+    /// absolute-numbered or movie filenames.
+    pub season: Option<u32>,
+    /// The episode number, if the filename encoded one. Absent for movies.
+    pub episode: Option<u32>,
+}
+
+fn release_group_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\s*\[[^]]*]\s*").unwrap())
+}
+
+fn season_episode_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)s(\d{1,2})e(\d{1,4})").unwrap())
+}
+
+fn x_episode_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)(\d{1,2})x(\d{1,4})").unwrap())
+}
+
+fn trailing_episode_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)(?:^|[\s._-])(?:e(?:p(?:isode)?)?\.?\s*)?(\d{1,4})(?:v\d)?\s*(?:\[[^]]*])?\s*$").unwrap())
+}
+
+fn tags_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?ix)
+            \[[^]]*] |                                     # [tags] anywhere
+            \([^)]*\) |                                    # (tags) anywhere, e.g. (1080p) or (2016)
+            \b(?:1080p|720p|480p|2160p|4k) \b |
+            \b(?:x26[45]|hevc|avc|h\.?26[45]) \b |
+            \b(?:bd|bdrip|web|webrip|dvd)\b |
+            \b(?:aac|flac|opus|dual-?audio) \b |
+            \b[0-9a-f]{8}\b                                # crc32 checksum
+        ")
+        .unwrap()
+    })
+}
+
+/// Tokenizes a release filename like `[Group] My Hero Academia - S06E12 (1080p).mkv` into a
+/// clean, query-ready title plus, if present, a season and episode number.
+pub fn parse_filename(filename: &str) -> ParsedFilename {
+    let without_extension = filename.rsplit_once('.').map_or(filename, |(name, _)| name);
+    let without_group = release_group_re().replace(without_extension, "");
+
+    let (season, episode) = if let Some(caps) = season_episode_re().captures(&without_group) {
+        (caps[1].parse().ok(), caps[2].parse().ok())
+    } else if let Some(caps) = x_episode_re().captures(&without_group) {
+        (caps[1].parse().ok(), caps[2].parse().ok())
+    } else {
+        (None, None)
+    };
+
+    let mut title = tags_re().replace_all(&without_group, " ").to_string();
+    title = season_episode_re().replace(&title, " ").to_string();
+    title = x_episode_re().replace(&title, " ").to_string();
+
+    let episode = episode.or_else(|| {
+        trailing_episode_re()
+            .captures(title.trim())
+            .and_then(|caps| caps[1].parse().ok())
+    });
+    if episode.is_some() && season.is_none() {
+        title = trailing_episode_re().replace(title.trim(), "").to_string();
+    }
+
+    title = title
+        .replace(['.', '_'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|c: char| c == '-' || c.is_whitespace())
+        .to_string();
+
+    ParsedFilename {
+        title,
+        season,
+        episode,
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let tmp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j - 1])
+            };
+            prev_diag = tmp;
+        }
+    }
+    row[b.len()]
+}
+
+/// Normalized edit-distance similarity between two titles, as a ratio in `0.0..=1.0` where
+/// `1.0` means identical (after lowercasing and stripping punctuation).
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (normalize(a), normalize(b));
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - (levenshtein(&a, &b) as f64 / max_len as f64)
+}
+
+/// What a [`MatchResult`] matched the filename against - a series (optionally resolved down to
+/// a specific episode), or a standalone movie.
+#[derive(Clone, Debug)]
+pub enum MatchedMedia {
+    Series {
+        series: Media<Series>,
+        /// `None` if no episode in [`Series::seasons`] matched the parsed episode number.
+        episode: Option<Media<Episode>>,
+    },
+    Movie(Media<MovieListing>),
+}
+
+/// A candidate match for a filename against the Crunchyroll catalog.
+#[derive(Clone, Debug)]
+pub struct MatchResult {
+    pub media: MatchedMedia,
+    /// How confident the match is, derived from the normalized title similarity between the
+    /// parsed filename and the matched title.
+    pub confidence: f64,
+}
+
+/// Options controlling how [`match_filename`] ranks and filters candidates.
+#[derive(Clone, Debug)]
+pub struct MatchOptions {
+    /// Minimum [`title_similarity`] a series must reach to be considered a candidate at all.
+    pub threshold: f64,
+    /// Maximum number of candidates returned for an ambiguous match.
+    pub max_candidates: usize,
+}
+
+impl Default for MatchOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.6,
+            max_candidates: 3,
+        }
+    }
+}
+
+async fn find_episode(series: &Series, parsed: &ParsedFilename) -> Result<Option<Media<Episode>>> {
+    let Some(episode_number) = parsed.episode else {
+        return Ok(None);
+    };
+
+    let seasons: Vec<Season> = series.seasons().await?;
+
+    if let Some(season_number) = parsed.season {
+        for season in &seasons {
+            if season.season_number == season_number {
+                let episodes = season.episodes().await?;
+                if let Some(episode) = episodes
+                    .into_iter()
+                    .find(|e| e.episode_number == episode_number)
+                {
+                    return Ok(Some(episode));
+                }
+            }
+        }
+        return Ok(None);
+    }
+
+    // No season was encoded in the filename (absolute numbering): walk the seasons in order and
+    // count cumulatively until we land on `episode_number`.
+    let mut cumulative = 0u32;
+    for season in &seasons {
+        let episodes = season.episodes().await?;
+        if let Some(index) = episode_number.checked_sub(cumulative + 1) {
+            if (index as usize) < episodes.len() {
+                return Ok(episodes.into_iter().nth(index as usize));
+            }
+        }
+        cumulative += episodes.len() as u32;
+    }
+
+    Ok(None)
+}
+
+/// Parses a single filename (see [`parse_filename`]) and matches it against the Crunchyroll catalog, returning up to
+/// [`MatchOptions::max_candidates`] candidates ranked by confidence instead of erroring on an
+/// ambiguous match.
+///
+/// A filename with no encoded episode number (e.g. a movie release) is only ever a plausible
+/// series match by accident, so in that case the movie listing catalog is searched as well
+/// instead of assuming every match must be a series.
+pub async fn match_filename(
+    crunchyroll: &Crunchyroll,
+    filename: &str,
+    options: &MatchOptions,
+) -> Result<Vec<MatchResult>> {
+    let parsed = parse_filename(filename);
+
+    let mut candidates: Vec<(f64, MatchedMedia)> = Vec::new();
+
+    let series_results = crunchyroll
+        .query(
+            &parsed.title,
+            QueryOptions::default().result_type(QueryType::Series),
+        )
+        .await?;
+    candidates.extend(
+        series_results
+            .series
+            .map(|bulk| bulk.items)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|searched| {
+                let confidence = title_similarity(&parsed.title, &searched.media.title);
+                (confidence >= options.threshold).then_some((
+                    confidence,
+                    MatchedMedia::Series {
+                        series: searched.media,
+                        episode: None,
+                    },
+                ))
+            }),
+    );
+
+    if parsed.episode.is_none() {
+        let movie_results = crunchyroll
+            .query(
+                &parsed.title,
+                QueryOptions::default().result_type(QueryType::MovieListing),
+            )
+            .await?;
+        candidates.extend(
+            movie_results
+                .movie_listing
+                .map(|bulk| bulk.items)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|searched| {
+                    let confidence = title_similarity(&parsed.title, &searched.media.title);
+                    (confidence >= options.threshold)
+                        .then_some((confidence, MatchedMedia::Movie(searched.media)))
+                }),
+        );
+    }
+
+    candidates.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    candidates.truncate(options.max_candidates);
+
+    let mut results = Vec::with_capacity(candidates.len());
+    for (confidence, media) in candidates {
+        let media = match media {
+            MatchedMedia::Series { series, .. } => {
+                let episode = find_episode(&series, &parsed).await?;
+                MatchedMedia::Series { series, episode }
+            }
+            movie @ MatchedMedia::Movie(_) => movie,
+        };
+        results.push(MatchResult { media, confidence });
+    }
+
+    Ok(results)
+}
+
+/// Batch version of [`match_filename`] over a slice of filenames, preserving input order. Each
+/// filename is matched independently, so one failing lookup doesn't prevent the others from
+/// being matched.
+pub async fn match_filenames(
+    crunchyroll: &Crunchyroll,
+    filenames: &[impl AsRef<str>],
+    options: &MatchOptions,
+) -> Vec<Result<Vec<MatchResult>>> {
+    let mut results = Vec::with_capacity(filenames.len());
+    for filename in filenames {
+        results.push(match_filename(crunchyroll, filename.as_ref(), options).await);
+    }
+    results
+}
@@ -0,0 +1,135 @@
+use crate::common::BulkResult;
+use crate::error::Result;
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::future::Future;
+
+struct PagerState<T> {
+    start: u32,
+    limit: u32,
+    total: Option<u32>,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+
+/// Turns a `start`/`limit`/`total` paged endpoint into a [`Stream`] which transparently fetches
+/// the next page (by advancing `start` by `limit`) once the current one is exhausted, stopping
+/// once `total` items have been yielded or a page comes back empty. Only one page is buffered
+/// at a time.
+///
+/// This removes the off-by-one bookkeeping callers would otherwise have to do themselves when
+/// consuming a full, possibly large, result set.
+pub(crate) fn paginate<T, F, Fut>(limit: u32, fetch: F) -> impl Stream<Item = Result<T>>
+where
+    F: Fn(u32, u32) -> Fut,
+    Fut: Future<Output = Result<BulkResult<T>>>,
+{
+    stream::unfold(
+        PagerState {
+            start: 0,
+            limit,
+            total: None,
+            buffer: VecDeque::new(),
+            done: false,
+        },
+        move |mut state| async move {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+            if let Some(total) = state.total {
+                if state.start >= total {
+                    return None;
+                }
+            }
+
+            match fetch(state.start, state.limit).await {
+                Ok(page) => {
+                    state.total = Some(page.total);
+                    state.start += state.limit;
+
+                    if page.items.is_empty() {
+                        state.done = true;
+                        return None;
+                    }
+
+                    state.buffer.extend(page.items);
+                    let item = state.buffer.pop_front().unwrap();
+                    Some((Ok(item), state))
+                }
+                Err(err) => {
+                    state.done = true;
+                    Some((Err(err), state))
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn paginate_yields_every_item_across_pages() {
+        let pages = Mutex::new(VecDeque::from([
+            BulkResult {
+                items: vec![1, 2],
+                total: 5,
+            },
+            BulkResult {
+                items: vec![3, 4],
+                total: 5,
+            },
+            BulkResult {
+                items: vec![5],
+                total: 5,
+            },
+        ]));
+        let calls = AtomicUsize::new(0);
+
+        let items: Vec<i32> = paginate(2, |_start, _limit| async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(pages.lock().unwrap().pop_front().expect("unexpected extra fetch"))
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn paginate_stops_on_empty_page_before_total_reached() {
+        let pages = Mutex::new(VecDeque::from([
+            BulkResult {
+                items: vec![1, 2],
+                total: 10,
+            },
+            BulkResult {
+                items: vec![],
+                total: 10,
+            },
+        ]));
+        let calls = AtomicUsize::new(0);
+
+        let items: Vec<i32> = paginate(2, |_start, _limit| async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(pages.lock().unwrap().pop_front().expect("unexpected extra fetch"))
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+
+        // `total` said 10 items existed, but an empty page arrived before we got there - the
+        // pager must trust the empty page and stop rather than looping on `total`.
+        assert_eq!(items, vec![1, 2]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
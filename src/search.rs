@@ -2,11 +2,14 @@ mod browse {
     use crate::categories::Category;
     use crate::common::BulkResult;
     use crate::media::MediaType;
+    use crate::pager::paginate;
     use crate::{enum_values, options, Crunchyroll, MediaCollection, Request, Result};
+    use futures_util::stream::Stream;
     use serde::Deserialize;
 
     /// Human readable implementation of [`SimulcastSeason`].
     #[derive(Clone, Debug, Default, Deserialize)]
+    #[cfg_attr(feature = "cache", derive(serde::Serialize))]
     #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
     #[cfg_attr(not(feature = "__test_strict"), serde(default))]
     pub struct SimulcastSeasonLocalization {
@@ -16,6 +19,7 @@ mod browse {
 
     /// A simulcast season.
     #[derive(Clone, Debug, Default, Deserialize, Request)]
+    #[cfg_attr(feature = "cache", derive(serde::Serialize))]
     #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
     #[cfg_attr(not(feature = "__test_strict"), serde(default))]
     pub struct SimulcastSeason {
@@ -56,6 +60,10 @@ mod browse {
     impl Crunchyroll {
         /// Browses the crunchyroll catalog filtered by the specified options and returns all found
         /// series and movies.
+        ///
+        /// Not covered by the `cache` feature: [`MediaCollection`] isn't (and shouldn't have to
+        /// be) `Serialize`, so there's nothing [`crate::cache::cached`] could round-trip through
+        /// a cache entry for this endpoint.
         pub async fn browse(&self, options: BrowseOptions) -> Result<BulkResult<MediaCollection>> {
             let endpoint = "https://www.crunchyroll.com/content/v1/browse";
             self.executor
@@ -66,16 +74,53 @@ mod browse {
                 .await
         }
 
+        /// Auto-paginating version of [`Crunchyroll::browse`]. Transparently refetches
+        /// subsequent pages (by advancing `start` by the options' `limit`) as the stream is
+        /// consumed, until every matching entry has been yielded.
+        pub fn browse_stream(
+            &self,
+            options: BrowseOptions,
+        ) -> impl Stream<Item = Result<MediaCollection>> {
+            let limit = options.limit.unwrap_or(20);
+            let executor = self.executor.clone();
+
+            paginate(limit, move |start, limit| {
+                let executor = executor.clone();
+                let options = options.clone().start(start).limit(limit);
+                async move {
+                    let endpoint = "https://www.crunchyroll.com/content/v1/browse";
+                    executor
+                        .get(endpoint)
+                        .query(&options.into_query())
+                        .apply_locale_query()
+                        .request()
+                        .await
+                }
+            })
+        }
+
         /// Returns all simulcast seasons.
         pub async fn simulcast_seasons(&self) -> Result<Vec<SimulcastSeason>> {
             let endpoint = "https://www.crunchyroll.com/content/v1/season_list";
-            Ok(self
-                .executor
-                .get(endpoint)
-                .apply_locale_query()
-                .request::<BulkResult<SimulcastSeason>>()
-                .await?
-                .items)
+
+            let fetch = || async {
+                self.executor
+                    .get(endpoint)
+                    .apply_locale_query()
+                    .request::<BulkResult<SimulcastSeason>>()
+                    .await
+            };
+
+            #[cfg(feature = "cache")]
+            let result = {
+                let key = format!("{endpoint}?locale={:?}", self.locale());
+                crate::cache::cached(crate::cache::CacheClass::SimulcastSeasons, &key, fetch)
+                    .await?
+            };
+            #[cfg(not(feature = "cache"))]
+            let result = fetch().await?;
+
+            Ok(result.items)
         }
     }
 }
@@ -84,10 +129,45 @@ mod query {
     use crate::common::{BulkResult, Request};
     use crate::error::{CrunchyrollError, CrunchyrollErrorContext, Result};
     use crate::media::{Episode, MovieListing, Series};
+    use crate::pager::paginate;
     use crate::{enum_values, options, Crunchyroll, Executor, Media, MediaCollection};
+    use chrono::{DateTime, Utc};
+    use futures_util::stream::Stream;
     use serde::Deserialize;
     use std::sync::Arc;
 
+    /// Ranking and relevance information Crunchyroll attaches to a media item returned from a
+    /// search. Useful for building "you might also like" style UIs without having to re-derive
+    /// a ranking of your own.
+    #[derive(Clone, Debug, Default, Deserialize)]
+    #[cfg_attr(feature = "__test_strict", serde(deny_unknown_fields))]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct SearchMetadata {
+        pub last_public: Option<DateTime<Utc>>,
+        pub rank: Option<u32>,
+        pub score: f64,
+        pub popularity_score: Option<f64>,
+    }
+
+    /// A [`MediaCollection`] together with the [`SearchMetadata`] Crunchyroll returned for it as
+    /// part of a search result.
+    #[derive(Clone, Debug, Default, Deserialize)]
+    #[cfg_attr(not(feature = "__test_strict"), serde(default))]
+    pub struct SearchedMediaCollection {
+        #[serde(flatten)]
+        pub media: MediaCollection,
+        #[serde(flatten)]
+        pub metadata: SearchMetadata,
+    }
+
+    /// A [`Media`] together with the [`SearchMetadata`] Crunchyroll returned for it as part of a
+    /// search result.
+    #[derive(Clone, Debug)]
+    pub struct SearchedMedia<T> {
+        pub media: Media<T>,
+        pub metadata: SearchMetadata,
+    }
+
     /// Results when querying Crunchyroll. Results depending on the input which was given via
     /// [`QueryOptions::result_type`]. If not specified, every field is populated, if one specific
     /// type, for example [`QueryType::Series`], were provided, only [`QueryResults::series`] will
@@ -100,20 +180,20 @@ mod query {
         #[serde(skip)]
         executor: Arc<Executor>,
 
-        pub top_results: Option<BulkResult<MediaCollection>>,
-        pub series: Option<BulkResult<Media<Series>>>,
-        pub movie_listing: Option<BulkResult<Media<MovieListing>>>,
-        pub episode: Option<BulkResult<Media<Episode>>>,
+        pub top_results: Option<BulkResult<SearchedMediaCollection>>,
+        pub series: Option<BulkResult<SearchedMedia<Series>>>,
+        pub movie_listing: Option<BulkResult<SearchedMedia<MovieListing>>>,
+        pub episode: Option<BulkResult<SearchedMedia<Episode>>>,
     }
 
     impl TryFrom<BulkResult<QueryBulkResult>> for QueryResults {
         type Error = CrunchyrollError;
 
         fn try_from(value: BulkResult<QueryBulkResult>) -> std::result::Result<Self, Self::Error> {
-            let mut top_results: Option<BulkResult<MediaCollection>> = None;
-            let mut series: Option<BulkResult<Media<Series>>> = None;
-            let mut movie_listing: Option<BulkResult<Media<MovieListing>>> = None;
-            let mut episode: Option<BulkResult<Media<Episode>>> = None;
+            let mut top_results: Option<BulkResult<SearchedMediaCollection>> = None;
+            let mut series: Option<BulkResult<SearchedMedia<Series>>> = None;
+            let mut movie_listing: Option<BulkResult<SearchedMedia<MovieListing>>> = None;
+            let mut episode: Option<BulkResult<SearchedMedia<Episode>>> = None;
 
             for item in value.items.clone() {
                 match item.result_type.as_str() {
@@ -128,8 +208,13 @@ mod query {
                             items: item
                                 .items
                                 .into_iter()
-                                .map(|i| i.try_into())
-                                .collect::<Result<Vec<Media<Series>>>>()?,
+                                .map(|i| {
+                                    Ok(SearchedMedia {
+                                        media: i.media.try_into()?,
+                                        metadata: i.metadata,
+                                    })
+                                })
+                                .collect::<Result<Vec<SearchedMedia<Series>>>>()?,
                             total: item.total,
                         })
                     }
@@ -138,8 +223,13 @@ mod query {
                             items: item
                                 .items
                                 .into_iter()
-                                .map(|i| i.try_into())
-                                .collect::<Result<Vec<Media<MovieListing>>>>()?,
+                                .map(|i| {
+                                    Ok(SearchedMedia {
+                                        media: i.media.try_into()?,
+                                        metadata: i.metadata,
+                                    })
+                                })
+                                .collect::<Result<Vec<SearchedMedia<MovieListing>>>>()?,
                             total: item.total,
                         })
                     }
@@ -148,8 +238,13 @@ mod query {
                             items: item
                                 .items
                                 .into_iter()
-                                .map(|i| i.try_into())
-                                .collect::<Result<Vec<Media<Episode>>>>()?,
+                                .map(|i| {
+                                    Ok(SearchedMedia {
+                                        media: i.media.try_into()?,
+                                        metadata: i.metadata,
+                                    })
+                                })
+                                .collect::<Result<Vec<SearchedMedia<Episode>>>>()?,
                             total: item.total,
                         })
                     }
@@ -181,7 +276,7 @@ mod query {
     struct QueryBulkResult {
         #[serde(rename = "type")]
         result_type: String,
-        items: Vec<MediaCollection>,
+        items: Vec<SearchedMediaCollection>,
         total: u32,
     }
 
@@ -203,6 +298,10 @@ mod query {
 
     impl Crunchyroll {
         /// Search the Crunchyroll catalog by a given query / string.
+        ///
+        /// Not covered by the `cache` feature: [`QueryResults`] carries its own executor handle
+        /// and isn't round-trippable through `Serialize`/`Deserialize`, unlike the plain
+        /// `BulkResult`-based endpoints.
         pub async fn query<S: AsRef<str>>(
             &self,
             query: S,
@@ -217,8 +316,144 @@ mod query {
                 .request()
                 .await
         }
+
+        /// Auto-paginating version of [`Crunchyroll::query`] over a single, populated result
+        /// category: the one selected by [`QueryOptions::result_type`], or the top results if it
+        /// is unset. Transparently refetches
+        /// subsequent pages as the stream is consumed, until every matching entry in that
+        /// category has been yielded.
+        pub fn query_stream<S: AsRef<str>>(
+            &self,
+            query: S,
+            options: QueryOptions,
+        ) -> impl Stream<Item = Result<MediaCollection>> {
+            let limit = options.limit.unwrap_or(20);
+            let executor = self.executor.clone();
+            let query = query.as_ref().to_string();
+
+            paginate(limit, move |start, limit| {
+                let executor = executor.clone();
+                let options = options.clone().limit(limit);
+                let query = query.clone();
+                async move {
+                    let endpoint = "https://www.crunchyroll.com/content/v1/search";
+                    let results: QueryResults = executor
+                        .get(endpoint)
+                        .query(&options.into_query())
+                        .query(&[("start", start.to_string())])
+                        .query(&[("q", query.as_str())])
+                        .apply_locale_query()
+                        .request()
+                        .await?;
+
+                    let category = match options.result_type {
+                        Some(QueryType::Series) => results.series.map(|r| BulkResult {
+                            items: r.items.into_iter().map(|i| i.media.into()).collect(),
+                            total: r.total,
+                        }),
+                        Some(QueryType::MovieListing) => {
+                            results.movie_listing.map(|r| BulkResult {
+                                items: r.items.into_iter().map(|i| i.media.into()).collect(),
+                                total: r.total,
+                            })
+                        }
+                        Some(QueryType::Episode) => results.episode.map(|r| BulkResult {
+                            items: r.items.into_iter().map(|i| i.media.into()).collect(),
+                            total: r.total,
+                        }),
+                        None => results.top_results.map(|r| BulkResult {
+                            items: r.items.into_iter().map(|i| i.media).collect(),
+                            total: r.total,
+                        }),
+                    };
+
+                    Ok(category.unwrap_or(BulkResult {
+                        items: vec![],
+                        total: 0,
+                    }))
+                }
+            })
+        }
+    }
+}
+
+mod similar {
+    use crate::common::BulkResult;
+    use crate::error::Result;
+    use crate::media::{MovieListing, Series};
+    use crate::options;
+    use crate::search::{SearchedMediaCollection, SearchMetadata};
+
+    fn popularity_score(metadata: &SearchMetadata) -> f64 {
+        metadata.popularity_score.unwrap_or(0.0)
+    }
+
+    /// `similar_to` returns a mix of series and movies, unlike `query()`'s type-bucketed
+    /// results, so the items are kept as [`SearchedMediaCollection`] instead of being forced
+    /// into a single `Media<T>` (which would error out the whole request the moment a
+    /// recommendation of the other type showed up).
+    fn sort_by_popularity(
+        mut result: BulkResult<SearchedMediaCollection>,
+    ) -> BulkResult<SearchedMediaCollection> {
+        result.items.sort_by(|a, b| {
+            popularity_score(&b.metadata)
+                .partial_cmp(&popularity_score(&a.metadata))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        result
+    }
+
+    options! {
+        SimilarOptions;
+        /// Limit of results to return.
+        limit(u32, "n") = Some(20),
+        /// Specifies the index from which the entries should be returned.
+        start(u32, "start") = None
+    }
+
+    impl Series {
+        /// Returns series and movies similar to this series, most popular first.
+        pub async fn similar(
+            &self,
+            options: SimilarOptions,
+        ) -> Result<BulkResult<SearchedMediaCollection>> {
+            let endpoint = format!(
+                "https://www.crunchyroll.com/content/v1/{}/similar_to",
+                self.id
+            );
+            let result = self
+                .executor
+                .get(endpoint)
+                .query(&options.into_query())
+                .apply_locale_query()
+                .request()
+                .await?;
+            Ok(sort_by_popularity(result))
+        }
+    }
+
+    impl MovieListing {
+        /// Returns series and movies similar to this movie listing, most popular first.
+        pub async fn similar(
+            &self,
+            options: SimilarOptions,
+        ) -> Result<BulkResult<SearchedMediaCollection>> {
+            let endpoint = format!(
+                "https://www.crunchyroll.com/content/v1/{}/similar_to",
+                self.id
+            );
+            let result = self
+                .executor
+                .get(endpoint)
+                .query(&options.into_query())
+                .apply_locale_query()
+                .request()
+                .await?;
+            Ok(sort_by_popularity(result))
+        }
     }
 }
 
 pub use browse::*;
 pub use query::*;
+pub use similar::*;
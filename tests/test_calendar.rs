@@ -0,0 +1,15 @@
+use crate::utils::SESSION;
+use crunchyroll_rs::ReleaseCalendarOptions;
+
+mod utils;
+
+#[tokio::test]
+async fn release_calendar() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(
+        crunchy
+            .release_calendar(ReleaseCalendarOptions::default())
+            .await
+    )
+}
@@ -0,0 +1,25 @@
+use crate::utils::Store;
+use crate::utils::SESSION;
+use crunchyroll_rs::{CuratedFeed, HomeFeedOptions};
+
+mod utils;
+
+static CURATED_FEED: Store<CuratedFeed> = Store::new(|| {
+    Box::pin(async {
+        let crunchy = SESSION.get().await?;
+        let feed = CuratedFeed::from_id(crunchy, "GGEH1X8Q8".to_string()).await?;
+        Ok(feed)
+    })
+});
+
+#[tokio::test]
+async fn curated_feed_from_id() {
+    assert_result!(CURATED_FEED.get().await)
+}
+
+#[tokio::test]
+async fn home_feed() {
+    let crunchy = SESSION.get().await.unwrap();
+
+    assert_result!(crunchy.home_feed(HomeFeedOptions::default()).await)
+}
@@ -0,0 +1,38 @@
+use crunchyroll_rs::{parse_filename, title_similarity};
+
+#[test]
+fn parse_filename_season_episode() {
+    let parsed = parse_filename("[Group] My Hero Academia - S06E12 (1080p).mkv");
+
+    assert_eq!(parsed.title, "My Hero Academia");
+    assert_eq!(parsed.season, Some(6));
+    assert_eq!(parsed.episode, Some(12));
+}
+
+#[test]
+fn parse_filename_x_notation() {
+    let parsed = parse_filename("[Group] My Hero Academia - 6x12 [x264][A1B2C3D4].mkv");
+
+    assert_eq!(parsed.season, Some(6));
+    assert_eq!(parsed.episode, Some(12));
+}
+
+#[test]
+fn parse_filename_movie_has_no_episode() {
+    let parsed = parse_filename("[Group] My Hero Academia The Movie (2020) [1080p].mkv");
+
+    assert_eq!(parsed.episode, None);
+}
+
+#[test]
+fn title_similarity_identical_after_normalization() {
+    assert_eq!(
+        title_similarity("My Hero Academia", "my hero academia!"),
+        1.0
+    );
+}
+
+#[test]
+fn title_similarity_penalizes_differences() {
+    assert!(title_similarity("My Hero Academia", "My Hero Academia Two") < 1.0);
+}
@@ -1,6 +1,6 @@
 use crate::utils::Store;
 use crate::utils::SESSION;
-use crunchyroll_rs::{Media, MovieListing};
+use crunchyroll_rs::{Media, MovieListing, SimilarOptions};
 
 mod utils;
 
@@ -21,3 +21,14 @@ async fn movie_listing_from_id() {
 async fn movies() {
     assert_result!(MOVIE_LISTING.get().await.unwrap().movies().await)
 }
+
+#[tokio::test]
+async fn movie_listing_similar() {
+    let movie_listing = MOVIE_LISTING.get().await.unwrap();
+
+    assert_result!(
+        movie_listing
+            .similar(SimilarOptions::default())
+            .await
+    )
+}